@@ -12,55 +12,254 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::cmp::Ordering;
+use std::cmp::Ordering::Equal;
+use std::cmp::Ordering::Greater;
+use std::cmp::Ordering::Less;
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_fuse_meta::meta::StatisticsOfColumns;
 use common_planners::Expression;
 use common_planners::ExpressionVisitor;
 use common_planners::Recursion;
 use common_tracing::tracing;
+use futures::stream;
+use futures::stream::StreamExt;
 use opendal::Operator;
 
 use crate::storages::fuse::io::load_bloom_filter_by_columns;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
+use crate::storages::index::sbbf_num_blocks;
 use crate::storages::index::BloomFilterIndexer;
 
+/// Default target false-positive probability for a bloom filter column that declares none.
+const DEFAULT_BLOOM_FPP: f64 = 0.01;
+/// Default expected distinct-value count for a bloom filter column that declares none.
+const DEFAULT_BLOOM_NDV: u64 = 100_000;
+
+/// Table option holding the comma-separated list of columns to bloom index.
+pub const OPT_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+/// Table option holding the comma-separated list of columns to exclude from bloom indexing.
+pub const OPT_BLOOM_INDEX_EXCLUDE_COLUMNS: &str = "bloom_index_exclude_columns";
+/// Table option holding the target false-positive probability.
+pub const OPT_BLOOM_INDEX_FPP: &str = "bloom_index_fpp";
+/// Table option holding the expected distinct-value count.
+pub const OPT_BLOOM_INDEX_NDV: &str = "bloom_index_ndv";
+
+/// Per-column bloom filter tuning, carried on the fuse table options.
+///
+/// Columns are indexed by default; an explicit `include` list restricts indexing to just those
+/// columns, while `exclude` drops columns from the otherwise-default set. When both are empty every
+/// indexable column is indexed. `fpp` and `ndv` size each column's filter and fall back to the
+/// engine defaults when unset.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BloomFilterOptions {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub fpp: Option<f64>,
+    pub ndv: Option<u64>,
+}
+
+impl BloomFilterOptions {
+    /// Resolve the options from a fuse table's options map (`TableMeta::options`), as set via
+    /// `CREATE TABLE ... bloom_index_columns='a,b' bloom_index_fpp='0.001'`. Unknown keys are
+    /// ignored here; a malformed FPP / NDV is returned as an error rather than silently falling
+    /// back to the defaults.
+    pub fn from_table_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        let columns = |key: &str| -> Vec<String> {
+            options
+                .get(key)
+                .map(|v| {
+                    v.split(',')
+                        .map(|c| c.trim().to_string())
+                        .filter(|c| !c.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let fpp = match options.get(OPT_BLOOM_INDEX_FPP) {
+            Some(v) => Some(v.parse::<f64>().map_err(|e| {
+                ErrorCode::BadArguments(format!("invalid {OPT_BLOOM_INDEX_FPP} '{v}': {e}"))
+            })?),
+            None => None,
+        };
+        let ndv = match options.get(OPT_BLOOM_INDEX_NDV) {
+            Some(v) => Some(v.parse::<u64>().map_err(|e| {
+                ErrorCode::BadArguments(format!("invalid {OPT_BLOOM_INDEX_NDV} '{v}': {e}"))
+            })?),
+            None => None,
+        };
+
+        Ok(Self {
+            include: columns(OPT_BLOOM_INDEX_COLUMNS),
+            exclude: columns(OPT_BLOOM_INDEX_EXCLUDE_COLUMNS),
+            fpp,
+            ndv,
+        })
+    }
+
+    /// Whether `column` should carry a bloom filter index under these options.
+    pub fn indexes(&self, column: &str) -> bool {
+        if !self.include.is_empty() {
+            return self.include.iter().any(|c| c == column);
+        }
+        !self.exclude.iter().any(|c| c == column)
+    }
+
+    /// The number of split-block bloom filter blocks to size a column's filter from, derived
+    /// from the declared FPP / NDV (or the engine defaults). `BloomFilterIndexer` does not read
+    /// it; the filters it builds are not sized from these options.
+    pub fn num_blocks(&self) -> usize {
+        sbbf_num_blocks(
+            self.ndv.unwrap_or(DEFAULT_BLOOM_NDV),
+            self.fpp.unwrap_or(DEFAULT_BLOOM_FPP),
+        )
+    }
+}
+
+#[cfg(test)]
+mod options_tests {
+    use super::*;
+
+    #[test]
+    fn default_indexes_every_column() {
+        let opts = BloomFilterOptions::default();
+        assert!(opts.indexes("a"));
+        assert!(opts.indexes("b"));
+    }
+
+    #[test]
+    fn include_restricts_exclude_drops() {
+        let include = BloomFilterOptions {
+            include: vec!["a".to_string()],
+            ..Default::default()
+        };
+        assert!(include.indexes("a"));
+        assert!(!include.indexes("b"));
+
+        let exclude = BloomFilterOptions {
+            exclude: vec!["b".to_string()],
+            ..Default::default()
+        };
+        assert!(exclude.indexes("a"));
+        assert!(!exclude.indexes("b"));
+    }
+
+    #[test]
+    fn tighter_fpp_sizes_more_blocks() {
+        let loose = BloomFilterOptions {
+            fpp: Some(0.1),
+            ndv: Some(100_000),
+            ..Default::default()
+        };
+        let tight = BloomFilterOptions {
+            fpp: Some(0.001),
+            ndv: Some(100_000),
+            ..Default::default()
+        };
+        assert!(tight.num_blocks() >= loose.num_blocks());
+        // defaults produce a usable, bounded size
+        assert!(BloomFilterOptions::default().num_blocks() >= 1);
+    }
+
+    #[test]
+    fn parses_from_table_options() {
+        let mut options = BTreeMap::new();
+        options.insert(OPT_BLOOM_INDEX_COLUMNS.to_string(), "a, b ,c".to_string());
+        options.insert(OPT_BLOOM_INDEX_FPP.to_string(), "0.001".to_string());
+        options.insert(OPT_BLOOM_INDEX_NDV.to_string(), "50000".to_string());
+
+        let opts = BloomFilterOptions::from_table_options(&options).unwrap();
+        assert_eq!(opts.include, vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string()
+        ]);
+        assert_eq!(opts.fpp, Some(0.001));
+        assert_eq!(opts.ndv, Some(50_000));
+        assert!(opts.indexes("a"));
+        assert!(!opts.indexes("z"));
+
+        // empty options resolve to the index-everything default
+        let empty = BloomFilterOptions::from_table_options(&BTreeMap::new()).unwrap();
+        assert_eq!(empty, BloomFilterOptions::default());
+    }
+
+    #[test]
+    fn rejects_malformed_fpp() {
+        let mut options = BTreeMap::new();
+        options.insert(OPT_BLOOM_INDEX_FPP.to_string(), "not-a-number".to_string());
+        assert!(BloomFilterOptions::from_table_options(&options).is_err());
+    }
+}
+
 #[async_trait::async_trait]
-pub trait BloomFilterPruner {
-    // returns ture, if target should NOT be pruned (false positive allowed)
-    async fn should_keep(&self, bloom_filter_block_path: &str) -> bool;
+pub trait BlockPruner {
+    // returns ture, if the block should NOT be pruned (false positive allowed)
+    async fn should_keep(&self, index_location: &str, block_stats: &StatisticsOfColumns) -> bool;
+
+    /// Batch variant of [`should_keep`](Self::should_keep): decide a whole set of blocks at once
+    /// so the per-block index loads can be issued concurrently instead of one serial await after
+    /// another. The returned vector is aligned with `blocks` positionally.
+    async fn prune(&self, blocks: Vec<(String, StatisticsOfColumns)>) -> Vec<bool> {
+        // conservative default: evaluate each block in turn. Implementations backed by network
+        // I/O should override this with a bounded-concurrency version.
+        let mut keep = Vec::with_capacity(blocks.len());
+        for (loc, stats) in &blocks {
+            keep.push(self.should_keep(loc, stats).await);
+        }
+        keep
+    }
 }
 
 /// dummy pruner that prunes nothing
 pub(crate) struct NonPruner;
 
 #[async_trait::async_trait]
-impl BloomFilterPruner for NonPruner {
-    async fn should_keep(&self, _loc: &str) -> bool {
+impl BlockPruner for NonPruner {
+    async fn should_keep(&self, _loc: &str, _stats: &StatisticsOfColumns) -> bool {
         true
     }
+
+    async fn prune(&self, blocks: Vec<(String, StatisticsOfColumns)>) -> Vec<bool> {
+        vec![true; blocks.len()]
+    }
 }
 
-struct BloomFilterIndexPruner {
+/// A pruner that decomposes the conjunctive filter and routes each conjunct to whichever
+/// index can decide it: equality / `IN` predicates are answered by the bloom filter index,
+/// range predicates (`<`, `<=`, `>`, `>=`) by the per-block column min/max statistics.
+///
+/// A block is pruned only when at least one conjunct proves that no row can match, so the two
+/// predicate classes are evaluated in a single pass sharing the same block metadata.
+struct ConjunctivePruner {
     ctx: Arc<dyn TableContext>,
     // columns that should be loaded from bloom filter block
     index_columns: Vec<String>,
-    // the expression that would be evaluate
+    // the full expression handed to the bloom filter index
     filter_expression: Expression,
+    // range conjuncts resolved against the min/max statistics
+    range_predicates: Vec<RangePredicate>,
     // the data accessor
     dal: Operator,
     // the schema of data being indexed
     data_schema: DataSchemaRef,
 }
 
-impl BloomFilterIndexPruner {
+impl ConjunctivePruner {
     pub fn new(
         ctx: Arc<dyn TableContext>,
         index_columns: Vec<String>,
         filter_expression: Expression,
+        range_predicates: Vec<RangePredicate>,
         dal: Operator,
         data_schema: DataSchemaRef,
     ) -> Self {
@@ -68,6 +267,7 @@ impl BloomFilterIndexPruner {
             ctx,
             index_columns,
             filter_expression,
+            range_predicates,
             dal,
             data_schema,
         }
@@ -76,8 +276,21 @@ impl BloomFilterIndexPruner {
 
 use self::util::*;
 #[async_trait::async_trait]
-impl BloomFilterPruner for BloomFilterIndexPruner {
-    async fn should_keep(&self, loc: &str) -> bool {
+impl BlockPruner for ConjunctivePruner {
+    async fn should_keep(&self, loc: &str, block_stats: &StatisticsOfColumns) -> bool {
+        // cheap first: a range conjunct can rule the block out using only the statistics that
+        // are already in the block meta, no extra I/O required.
+        for predicate in &self.range_predicates {
+            if predicate.prunes(block_stats) {
+                return false;
+            }
+        }
+
+        // no equality / IN columns to probe, nothing left the bloom index can decide
+        if self.index_columns.is_empty() {
+            return true;
+        }
+
         // load bloom filter index, and try pruning according to filter expression
         match filter_block_by_bloom_index(
             self.ctx.clone(),
@@ -97,41 +310,141 @@ impl BloomFilterPruner for BloomFilterIndexPruner {
             }
         }
     }
+
+    async fn prune(&self, blocks: Vec<(String, StatisticsOfColumns)>) -> Vec<bool> {
+        // pull the I/O concurrency limit from the context so the parallelism matches the rest of
+        // the storage layer; fall back to a small default if the setting is unavailable.
+        let concurrency = self
+            .ctx
+            .get_settings()
+            .get_max_storage_io_requests()
+            .map(|v| v as usize)
+            .unwrap_or(16)
+            .max(1);
+
+        prune_concurrently(blocks, concurrency, |loc, stats| async move {
+            self.should_keep(&loc, &stats).await
+        })
+        .await
+    }
+}
+
+/// Decide a batch of blocks with bounded concurrency, issuing up to `concurrency` per-block
+/// decisions at once. The returned vector is aligned with `blocks` positionally even though the
+/// underlying futures complete out of order — each block's index is carried through the unordered
+/// stream and written back into its slot.
+async fn prune_concurrently<F, Fut>(
+    blocks: Vec<(String, StatisticsOfColumns)>,
+    concurrency: usize,
+    decide: F,
+) -> Vec<bool>
+where
+    F: Fn(String, StatisticsOfColumns) -> Fut,
+    Fut: std::future::Future<Output = bool>,
+{
+    let mut keep = vec![true; blocks.len()];
+    let mut results = stream::iter(blocks.into_iter().enumerate())
+        .map(|(idx, (loc, stats))| {
+            let fut = decide(loc, stats);
+            async move { (idx, fut.await) }
+        })
+        .buffer_unordered(concurrency.max(1));
+    while let Some((idx, decision)) = results.next().await {
+        keep[idx] = decision;
+    }
+    keep
 }
 
 /// try to build the pruner.
-/// if `filter_expr` is none, or is not applicable, e.g. have no point queries
-/// a [NonPruner] will be return, which prunes nothing.
-/// otherwise, a [BloomFilterIndexer] backed pruner will be return
-pub fn new_bloom_filter_pruner(
+/// if `filter_expr` is none, or carries no prunable conjunct (neither a point query nor a
+/// range predicate on a known column), a [NonPruner] will be return, which prunes nothing.
+/// otherwise, a [ConjunctivePruner] that combines the bloom filter index with the block
+/// min/max statistics will be returned.
+pub fn new_block_pruner(
     ctx: &Arc<dyn TableContext>,
     filter_expr: Option<&Expression>,
     schema: &DataSchemaRef,
     dal: Operator,
-) -> Result<Arc<dyn BloomFilterPruner + Send + Sync>> {
+    bloom_options: &BloomFilterOptions,
+) -> Result<Arc<dyn BlockPruner + Send + Sync>> {
     if let Some(expr) = filter_expr {
-        // check if there were applicable filter conditions
+        // equality / IN conjuncts, resolved through the bloom filter index. Columns the user
+        // excluded from bloom indexing (via the per-column bloom filter options) carry no index,
+        // so they are dropped here and left to the other pruning paths.
         let point_query_cols = columns_names_of_eq_expressions(expr)?;
-        if !point_query_cols.is_empty() {
-            // convert to bloom filter block's column names
-            let filter_block_cols = point_query_cols
-                .into_iter()
-                .map(|n| BloomFilterIndexer::to_bloom_column_name(&n))
-                .collect();
-            return Ok(Arc::new(BloomFilterIndexPruner::new(
+        let had_point_queries = !point_query_cols.is_empty();
+        let filter_block_cols: Vec<String> = point_query_cols
+            .into_iter()
+            .filter(|n| bloom_options.indexes(n))
+            .map(|n| BloomFilterIndexer::to_bloom_column_name(&n))
+            .collect();
+
+        // if the query had point predicates but every one of their columns was excluded from
+        // bloom indexing, bloom probing is disabled for this scan; surface that rather than
+        // degrading silently. Range conjuncts are unaffected and still prune via min/max.
+        if had_point_queries && filter_block_cols.is_empty() {
+            tracing::warn!(
+                "bloom filter pruning skipped: none of the point-query columns are bloom indexed"
+            );
+        }
+
+        // range conjuncts, resolved against the per-block min/max statistics
+        let range_predicates = range_predicates_of(expr, schema);
+
+        if !filter_block_cols.is_empty() || !range_predicates.is_empty() {
+            return Ok(Arc::new(ConjunctivePruner::new(
                 ctx.clone(),
                 filter_block_cols,
                 expr.clone(),
+                range_predicates,
                 dal,
                 schema.clone(),
             )));
         } else {
-            tracing::debug!("no point filters found, using NonPruner");
+            tracing::debug!("no prunable conjunct found, using NonPruner");
         }
     }
     Ok(Arc::new(NonPruner))
 }
 
+#[cfg(test)]
+mod prune_tests {
+    use super::*;
+
+    fn blocks(n: usize) -> Vec<(String, StatisticsOfColumns)> {
+        (0..n)
+            .map(|i| (i.to_string(), StatisticsOfColumns::default()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn prune_concurrently_preserves_positional_alignment() {
+        // decide the even-indexed blocks as keep, and delay earlier indices longer so the futures
+        // complete out of order — the result must still be aligned to the input positions.
+        let keep = prune_concurrently(blocks(10), 4, |loc, _stats| async move {
+            let idx: u64 = loc.parse().unwrap();
+            tokio::time::sleep(std::time::Duration::from_millis((10 - idx) * 2)).await;
+            idx % 2 == 0
+        })
+        .await;
+        let expected: Vec<bool> = (0..10).map(|i| i % 2 == 0).collect();
+        assert_eq!(keep, expected);
+    }
+
+    #[tokio::test]
+    async fn prune_concurrently_handles_zero_concurrency() {
+        // a degenerate concurrency must not deadlock or drop blocks
+        let keep = prune_concurrently(blocks(3), 0, |_loc, _stats| async move { true }).await;
+        assert_eq!(keep, vec![true; 3]);
+    }
+
+    #[tokio::test]
+    async fn non_pruner_keeps_everything_with_matching_length() {
+        let keep = NonPruner.prune(blocks(5)).await;
+        assert_eq!(keep, vec![true; 5]);
+    }
+}
+
 mod util {
     use super::*;
     #[tracing::instrument(level = "debug", skip_all)]
@@ -154,9 +467,59 @@ mod util {
         )
         .await?;
 
-        // figure it out
-        BloomFilterIndexer::from_bloom_block(schema.clone(), filter_block, ctx)?
-            .maybe_true(filter_expr)
+        // figure it out: OR-combine the per-value membership tests so the block is kept when
+        // *any* candidate literal of an IN / OR-of-equalities may be present.
+        let indexer = BloomFilterIndexer::from_bloom_block(schema.clone(), filter_block, ctx)?;
+        eval_maybe_true(filter_expr, &|e| indexer.maybe_true(e))
+    }
+
+    /// Evaluate `expr` against the bloom filter index, returning whether the block may contain a
+    /// matching row (false positives allowed, no false negatives).
+    ///
+    /// Conjunctions and disjunctions are combined structurally, and an `IN (lit, ..)` is expanded
+    /// into one equality probe per literal that are OR-combined, so a block is kept when any of the
+    /// literals may be present. Every leaf that is not a membership predicate the index can decide
+    /// (a range, say) is delegated to `probe` unchanged, which keeps the block — hence an `OR`
+    /// mixing a prunable equality with a non-prunable predicate always keeps the block.
+    pub fn eval_maybe_true<P>(expr: &Expression, probe: &P) -> Result<bool>
+    where P: Fn(&Expression) -> Result<bool> {
+        match expr {
+            Expression::BinaryExpression { left, op, right } if op.as_str() == "and" => {
+                Ok(eval_maybe_true(left, probe)? && eval_maybe_true(right, probe)?)
+            }
+            Expression::BinaryExpression { left, op, right } if op.as_str() == "or" => {
+                Ok(eval_maybe_true(left, probe)? || eval_maybe_true(right, probe)?)
+            }
+            Expression::ScalarFunction { op, args } if op.eq_ignore_ascii_case("in") => {
+                match args.split_first() {
+                    Some((Expression::Column(column), rest))
+                        if !rest.is_empty()
+                            && rest
+                                .iter()
+                                .all(|a| matches!(a, Expression::Literal { .. })) =>
+                    {
+                        for literal in rest {
+                            if probe(&eq_expression(column, literal))? {
+                                return Ok(true);
+                            }
+                        }
+                        Ok(false)
+                    }
+                    // not a plain `col IN (lit, ..)`, leave it to the probe as-is
+                    _ => probe(expr),
+                }
+            }
+            _ => probe(expr),
+        }
+    }
+
+    /// Build the `column = literal` equality used to probe a single IN / OR candidate.
+    fn eq_expression(column: &str, literal: &Expression) -> Expression {
+        Expression::BinaryExpression {
+            left: Box::new(Expression::Column(column.to_string())),
+            op: "=".to_string(),
+            right: Box::new(literal.clone()),
+        }
     }
 
     struct PointQueryVisitor {
@@ -166,22 +529,81 @@ mod util {
 
     impl ExpressionVisitor for PointQueryVisitor {
         fn pre_visit(mut self, expr: &Expression) -> Result<Recursion<Self>> {
-            // TODO
-            // 1. only binary op "=" is considered, which is NOT enough
-            // 2. should combine this logic with BloomFilterIndexer
+            // A column is a point-query candidate if the predicate that references it is a
+            // membership test against a fixed set of literals: `col = lit`, `col IN (lit, ..)`,
+            // or a disjunction of those on a *single* column. The bloom filter can then keep the
+            // block when *any* of the literals may be present.
+            //
+            // Disjunctions are handled explicitly here rather than by letting the generic
+            // traversal collect every nested `=`. That matters for conservativeness: an `OR`
+            // that mixes a prunable equality with a non-prunable predicate (e.g. a range) cannot
+            // be used to prune at all, so we must not harvest the equality out of it.
             match expr {
-                Expression::BinaryExpression { left, op, right } if op.as_str() == "=" => {
-                    match (left.as_ref(), right.as_ref()) {
-                        (Expression::Column(column), Expression::Literal { .. })
-                        | (Expression::Literal { .. }, Expression::Column(column)) => {
-                            self.columns.insert(column.clone());
-                            Ok(Recursion::Stop(self))
-                        }
-                        _ => Ok(Recursion::Continue(self)),
+                Expression::BinaryExpression { op, .. } if op.as_str() == "or" => {
+                    if let Some(column) = disjunction_on_single_column(expr) {
+                        self.columns.insert(column);
+                    }
+                    // either way the whole disjunction has been decided, do not descend into it
+                    Ok(Recursion::Stop(self))
+                }
+                _ => {
+                    if let Some(column) = point_query_column(expr) {
+                        self.columns.insert(column);
+                        Ok(Recursion::Stop(self))
+                    } else {
+                        Ok(Recursion::Continue(self))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the column name if `expr` is a single-column membership test, i.e. `col = lit`
+    /// or `col IN (lit, lit, ...)`.
+    fn point_query_column(expr: &Expression) -> Option<String> {
+        match expr {
+            Expression::BinaryExpression { left, op, right } if op.as_str() == "=" => {
+                match (left.as_ref(), right.as_ref()) {
+                    (Expression::Column(column), Expression::Literal { .. })
+                    | (Expression::Literal { .. }, Expression::Column(column)) => {
+                        Some(column.clone())
                     }
+                    _ => None,
                 }
-                _ => Ok(Recursion::Continue(self)),
             }
+            Expression::ScalarFunction { op, args } if op.eq_ignore_ascii_case("in") => {
+                // first argument is the probed column, the rest are the candidate literals
+                match args.split_first() {
+                    Some((Expression::Column(column), rest))
+                        if !rest.is_empty()
+                            && rest
+                                .iter()
+                                .all(|a| matches!(a, Expression::Literal { .. })) =>
+                    {
+                        Some(column.clone())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the column name if `expr` is an `OR` tree whose leaves are all membership tests on
+    /// the *same* column. Any leaf that is not a point query on that column makes the whole
+    /// disjunction non-prunable and yields `None`.
+    fn disjunction_on_single_column(expr: &Expression) -> Option<String> {
+        match expr {
+            Expression::BinaryExpression { left, op, right } if op.as_str() == "or" => {
+                let l = disjunction_on_single_column(left)?;
+                let r = disjunction_on_single_column(right)?;
+                if l == r {
+                    Some(l)
+                } else {
+                    None
+                }
+            }
+            _ => point_query_column(expr),
         }
     }
 
@@ -194,4 +616,342 @@ mod util {
             .accept(visitor)
             .map(|r| r.columns.into_iter().collect())
     }
+
+    #[cfg(test)]
+    mod visitor_tests {
+        use common_planners::col;
+        use common_planners::lit;
+
+        use super::*;
+
+        #[test]
+        fn collects_eq_in_and_single_column_or() {
+            // col = lit
+            let cols = columns_names_of_eq_expressions(&col("a").eq(lit(1i64))).unwrap();
+            assert_eq!(cols, vec!["a".to_string()]);
+
+            // a = 1 OR a = 2  (same column)
+            let or_same = col("a").eq(lit(1i64)).or(col("a").eq(lit(2i64)));
+            assert_eq!(
+                columns_names_of_eq_expressions(&or_same).unwrap(),
+                vec!["a".to_string()]
+            );
+
+            // a IN (1, 2, 3)
+            let in_expr = Expression::ScalarFunction {
+                op: "in".to_string(),
+                args: vec![col("a"), lit(1i64), lit(2i64), lit(3i64)],
+            };
+            assert_eq!(
+                columns_names_of_eq_expressions(&in_expr).unwrap(),
+                vec!["a".to_string()]
+            );
+        }
+
+        #[test]
+        fn is_conservative_on_mixed_disjunctions() {
+            // a = 1 OR b > 2  : a range branch makes the whole OR non-prunable
+            let mixed = col("a").eq(lit(1i64)).or(col("b").gt(lit(2i64)));
+            assert!(columns_names_of_eq_expressions(&mixed).unwrap().is_empty());
+
+            // a = 1 OR b = 2  : different columns, not a same-column disjunction
+            let diff = col("a").eq(lit(1i64)).or(col("b").eq(lit(2i64)));
+            assert!(columns_names_of_eq_expressions(&diff).unwrap().is_empty());
+
+            // a = 1 AND (b = 2 OR c > 3) : top-level AND keeps `a`, drops the mixed OR
+            let conj = col("a")
+                .eq(lit(1i64))
+                .and(col("b").eq(lit(2i64)).or(col("c").gt(lit(3i64))));
+            assert_eq!(
+                columns_names_of_eq_expressions(&conj).unwrap(),
+                vec!["a".to_string()]
+            );
+        }
+
+        // a probe that reports a fixed set of `column = literal` equalities as "maybe present",
+        // and treats everything else (e.g. ranges) conservatively as "maybe present".
+        fn probe_for(present: &[(&str, i64)]) -> impl Fn(&Expression) -> Result<bool> + '_ {
+            move |expr: &Expression| {
+                if let Expression::BinaryExpression { left, op, right } = expr {
+                    if op.as_str() == "=" {
+                        if let (Expression::Column(c), Expression::Literal { value, .. }) =
+                            (left.as_ref(), right.as_ref())
+                        {
+                            let hit = present.iter().any(|(pc, pv)| {
+                                pc == c && matches!(value, DataValue::Int64(v) if v == pv)
+                            });
+                            return Ok(hit);
+                        }
+                    }
+                }
+                // non-equality leaf (range, etc.): cannot be disproven by the bloom index
+                Ok(true)
+            }
+        }
+
+        #[test]
+        fn in_list_is_kept_when_any_literal_may_be_present() {
+            let in_expr = Expression::ScalarFunction {
+                op: "in".to_string(),
+                args: vec![col("a"), lit(1i64), lit(2i64), lit(3i64)],
+            };
+            // only a = 2 is present -> keep
+            assert!(eval_maybe_true(&in_expr, &probe_for(&[("a", 2)])).unwrap());
+            // none of the literals present -> prune
+            assert!(!eval_maybe_true(&in_expr, &probe_for(&[("a", 9)])).unwrap());
+        }
+
+        #[test]
+        fn or_of_equalities_is_kept_when_any_branch_may_be_present() {
+            let or_expr = col("a").eq(lit(1i64)).or(col("a").eq(lit(2i64)));
+            assert!(eval_maybe_true(&or_expr, &probe_for(&[("a", 1)])).unwrap());
+            assert!(!eval_maybe_true(&or_expr, &probe_for(&[("a", 7)])).unwrap());
+        }
+
+        #[test]
+        fn or_mixing_equality_with_range_always_keeps() {
+            // a = 1 OR a > 5 : even with a = 1 absent, the range branch keeps the block
+            let mixed = col("a").eq(lit(1i64)).or(col("a").gt(lit(5i64)));
+            assert!(eval_maybe_true(&mixed, &probe_for(&[])).unwrap());
+        }
+
+        #[test]
+        fn conjunction_prunes_when_either_side_absent() {
+            // a = 1 AND a = 2 : if a = 2 is absent the whole conjunction prunes
+            let conj = col("a").eq(lit(1i64)).and(col("a").eq(lit(2i64)));
+            assert!(!eval_maybe_true(&conj, &probe_for(&[("a", 1)])).unwrap());
+            assert!(eval_maybe_true(&conj, &probe_for(&[("a", 1), ("a", 2)])).unwrap());
+        }
+    }
+
+    /// the comparison carried by a range predicate, written as `column <op> literal`
+    #[derive(Clone, Copy)]
+    enum RangeOp {
+        Lt,
+        Le,
+        Gt,
+        Ge,
+    }
+
+    impl RangeOp {
+        fn from_str(op: &str) -> Option<Self> {
+            match op {
+                "<" => Some(RangeOp::Lt),
+                "<=" => Some(RangeOp::Le),
+                ">" => Some(RangeOp::Gt),
+                ">=" => Some(RangeOp::Ge),
+                _ => None,
+            }
+        }
+
+        /// the operator to use after swapping the operands, so `literal <op> column` can be
+        /// normalised into `column <flipped> literal`.
+        fn flip(self) -> Self {
+            match self {
+                RangeOp::Lt => RangeOp::Gt,
+                RangeOp::Le => RangeOp::Ge,
+                RangeOp::Gt => RangeOp::Lt,
+                RangeOp::Ge => RangeOp::Le,
+            }
+        }
+    }
+
+    /// a single range conjunct, pre-resolved to the column's position in the schema so that
+    /// pruning only touches the block min/max statistics.
+    pub struct RangePredicate {
+        col_index: u32,
+        op: RangeOp,
+        literal: DataValue,
+    }
+
+    impl RangePredicate {
+        /// returns true if the block can be pruned, i.e. the statistics prove that no value in
+        /// the column can satisfy `column <op> literal`. Missing statistics (or a null bound)
+        /// are treated conservatively as "may match".
+        pub fn prunes(&self, stats: &StatisticsOfColumns) -> bool {
+            let col_stats = match stats.get(&self.col_index) {
+                Some(s) => s,
+                None => return false,
+            };
+            if col_stats.min.is_null() || col_stats.max.is_null() {
+                return false;
+            }
+            match self.op {
+                // every value >= min > literal  =>  nothing can be < / <= literal
+                RangeOp::Lt => matches!(cmp(&col_stats.min, &self.literal), Some(Greater | Equal)),
+                RangeOp::Le => matches!(cmp(&col_stats.min, &self.literal), Some(Greater)),
+                // every value <= max < literal  =>  nothing can be > / >= literal
+                RangeOp::Gt => matches!(cmp(&col_stats.max, &self.literal), Some(Less | Equal)),
+                RangeOp::Ge => matches!(cmp(&col_stats.max, &self.literal), Some(Less)),
+            }
+        }
+    }
+
+    /// Order two [`DataValue`]s by *value*, reconciling the numeric variants first.
+    ///
+    /// `DataValue`'s derived `PartialOrd` orders by enum variant, so an `UInt64` stat and an
+    /// `Int64`/`Float64` literal — routine after parse and type coercion — would compare wrongly
+    /// and could prune a block that actually contains matching rows. Integers are compared in
+    /// `i128`, anything involving a float in `f64`, strings and booleans within their own type.
+    /// Incomparable pairs yield `None`, which the caller treats conservatively as "may match".
+    fn cmp(a: &DataValue, b: &DataValue) -> Option<Ordering> {
+        if let (Some(x), Some(y)) = (as_i128(a), as_i128(b)) {
+            return Some(x.cmp(&y));
+        }
+        if let (Some(x), Some(y)) = (as_f64(a), as_f64(b)) {
+            return x.partial_cmp(&y);
+        }
+        match (a, b) {
+            (DataValue::String(x), DataValue::String(y)) => Some(x.cmp(y)),
+            (DataValue::Boolean(x), DataValue::Boolean(y)) => Some(x.cmp(y)),
+            _ => None,
+        }
+    }
+
+    fn as_i128(v: &DataValue) -> Option<i128> {
+        match v {
+            DataValue::Int64(x) => Some(*x as i128),
+            DataValue::UInt64(x) => Some(*x as i128),
+            _ => None,
+        }
+    }
+
+    fn as_f64(v: &DataValue) -> Option<f64> {
+        match v {
+            DataValue::Int64(x) => Some(*x as f64),
+            DataValue::UInt64(x) => Some(*x as f64),
+            DataValue::Float64(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    /// Collect the range conjuncts that can be decided from the block min/max statistics.
+    ///
+    /// Only the top-level conjunction is descended: a comparison nested under an `OR` cannot be
+    /// used to prune on its own, so it is left out to stay conservative.
+    pub fn range_predicates_of(filter_expr: &Expression, schema: &DataSchemaRef) -> Vec<RangePredicate> {
+        let mut predicates = Vec::new();
+        collect_range_predicates(filter_expr, schema, &mut predicates);
+        predicates
+    }
+
+    fn collect_range_predicates(
+        expr: &Expression,
+        schema: &DataSchemaRef,
+        out: &mut Vec<RangePredicate>,
+    ) {
+        match expr {
+            Expression::BinaryExpression { left, op, right } if op.as_str() == "and" => {
+                collect_range_predicates(left, schema, out);
+                collect_range_predicates(right, schema, out);
+            }
+            Expression::BinaryExpression { left, op, right } => {
+                if let Some(op) = RangeOp::from_str(op.as_str()) {
+                    if let Some(predicate) = range_predicate(left, op, right, schema) {
+                        out.push(predicate);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn range_predicate(
+        left: &Expression,
+        op: RangeOp,
+        right: &Expression,
+        schema: &DataSchemaRef,
+    ) -> Option<RangePredicate> {
+        let (column, literal, op) = match (left, right) {
+            (Expression::Column(column), Expression::Literal { value, .. }) => (column, value, op),
+            (Expression::Literal { value, .. }, Expression::Column(column)) => {
+                (column, value, op.flip())
+            }
+            _ => return None,
+        };
+        let col_index = schema.index_of(column).ok()? as u32;
+        Some(RangePredicate {
+            col_index,
+            op,
+            literal: literal.clone(),
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::collections::HashMap;
+
+        use common_fuse_meta::meta::ColumnStatistics;
+
+        use super::*;
+
+        fn stats(min: DataValue, max: DataValue) -> StatisticsOfColumns {
+            let mut s = HashMap::new();
+            s.insert(0u32, ColumnStatistics {
+                min,
+                max,
+                null_count: 0,
+                in_memory_size: 0,
+                distinct_of_values: None,
+            });
+            s
+        }
+
+        fn predicate(op: RangeOp, literal: DataValue) -> RangePredicate {
+            RangePredicate {
+                col_index: 0,
+                op,
+                literal,
+            }
+        }
+
+        #[test]
+        fn cmp_reconciles_numeric_variants() {
+            // UInt64 stat vs Int64 literal must order by value, not by enum variant
+            assert_eq!(
+                cmp(&DataValue::UInt64(5), &DataValue::Int64(10)),
+                Some(Ordering::Less)
+            );
+            assert_eq!(
+                cmp(&DataValue::Int64(10), &DataValue::Float64(10.0)),
+                Some(Ordering::Equal)
+            );
+            assert_eq!(
+                cmp(&DataValue::Float64(2.5), &DataValue::Int64(2)),
+                Some(Ordering::Greater)
+            );
+            // incomparable categories stay undecided
+            assert_eq!(
+                cmp(&DataValue::Int64(1), &DataValue::String(b"a".to_vec())),
+                None
+            );
+        }
+
+        #[test]
+        fn range_prunes_only_when_disjoint() {
+            // block holds [10, 20] as UInt64
+            let block = stats(DataValue::UInt64(10), DataValue::UInt64(20));
+
+            // c < 10 : min(10) >= 10  => prune
+            assert!(predicate(RangeOp::Lt, DataValue::Int64(10)).prunes(&block));
+            // c < 11 : min(10) < 11   => keep
+            assert!(!predicate(RangeOp::Lt, DataValue::Int64(11)).prunes(&block));
+            // c > 20 : max(20) <= 20  => prune
+            assert!(predicate(RangeOp::Gt, DataValue::Int64(20)).prunes(&block));
+            // c >= 20 : max(20) >= 20 => keep
+            assert!(!predicate(RangeOp::Ge, DataValue::Int64(20)).prunes(&block));
+            // c <= 9 : min(10) > 9    => prune
+            assert!(predicate(RangeOp::Le, DataValue::Int64(9)).prunes(&block));
+        }
+
+        #[test]
+        fn range_keeps_on_cross_type_incomparable_or_null() {
+            let block = stats(DataValue::UInt64(10), DataValue::UInt64(20));
+            // string literal vs integer stat => undecided => keep
+            assert!(!predicate(RangeOp::Gt, DataValue::String(b"z".to_vec())).prunes(&block));
+            // null bounds => keep
+            let null_block = stats(DataValue::Null, DataValue::Null);
+            assert!(!predicate(RangeOp::Lt, DataValue::Int64(0)).prunes(&null_block));
+        }
+    }
 }
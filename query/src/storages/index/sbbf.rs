@@ -0,0 +1,311 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! The split-block bloom filter (SBBF) layout used by Parquet.
+//!
+//! The filter is partitioned into independent blocks of 256 bits (eight 32-bit words). A 64-bit
+//! hash picks one block from its high 32 bits and sets eight bits within that block derived from
+//! its low 32 bits. Because every probe touches a single cache-line-sized block the layout is
+//! cache friendly, and its block and bit layout matches Parquet / DataFusion's `Sbbf`. The
+//! serialized form produced by [`Sbbf::to_bytes`] prefixes the words with a version tag, which a
+//! plain Parquet bitset does not carry.
+
+/// Number of 32-bit words in a block. A block is therefore 256 bits wide.
+const WORDS_PER_BLOCK: usize = 8;
+
+/// The eight odd salt constants mandated by the Parquet SBBF specification. Each salt maps the
+/// low 32 bits of a hash to one bit position in its word.
+const SALT: [u32; WORDS_PER_BLOCK] = [
+    0x47b6_137b,
+    0x4497_4d91,
+    0x8824_ad5b,
+    0xa2b7_289d,
+    0x7054_95c7,
+    0x2df1_424b,
+    0x9efc_4947,
+    0x5c6b_fb31,
+];
+
+/// Version tag written alongside the filter bytes so that indexes produced by older encodings
+/// remain readable.
+pub const SBBF_VERSION: u16 = 1;
+
+/// A split-block bloom filter over 64-bit hashes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sbbf {
+    // one block == WORDS_PER_BLOCK words; `blocks.len()` is always a multiple of WORDS_PER_BLOCK
+    blocks: Vec<u32>,
+}
+
+impl Sbbf {
+    /// Create an empty filter sized for `num_blocks` blocks (at least one).
+    pub fn with_num_blocks(num_blocks: usize) -> Self {
+        let num_blocks = num_blocks.max(1);
+        Self {
+            blocks: vec![0; num_blocks * WORDS_PER_BLOCK],
+        }
+    }
+
+    /// Create an empty filter sized from the expected number of distinct values and a target
+    /// false-positive probability.
+    pub fn with_ndv_fpp(ndv: u64, fpp: f64) -> Self {
+        Self::with_num_blocks(num_blocks(ndv, fpp))
+    }
+
+    /// Reconstruct a filter from its raw little-endian words, e.g. as stored in the index block.
+    pub fn from_words(blocks: Vec<u32>) -> Self {
+        debug_assert!(
+            !blocks.is_empty() && blocks.len() % WORDS_PER_BLOCK == 0,
+            "sbbf word count must be a non-zero multiple of the block width"
+        );
+        Self { blocks }
+    }
+
+    /// The raw words backing the filter, for serialisation.
+    pub fn words(&self) -> &[u32] {
+        &self.blocks
+    }
+
+    /// Serialise the filter with a leading [`SBBF_VERSION`] tag so that a reader can tell this
+    /// layout apart from the legacy databend encoding and stay backward compatible.
+    ///
+    /// Layout: `u16` version, little endian, followed by the block words, each little endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(2 + self.blocks.len() * 4);
+        buf.extend_from_slice(&SBBF_VERSION.to_le_bytes());
+        for word in &self.blocks {
+            buf.extend_from_slice(&word.to_le_bytes());
+        }
+        buf
+    }
+
+    /// Parse the bytes produced by [`to_bytes`](Self::to_bytes). Returns `None` for a version tag
+    /// this build does not understand (e.g. an older databend encoding), so the caller can fall
+    /// back to the legacy reader instead of mis-decoding it.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 2 {
+            return None;
+        }
+        let version = u16::from_le_bytes([bytes[0], bytes[1]]);
+        if version != SBBF_VERSION {
+            return None;
+        }
+        let body = &bytes[2..];
+        if body.is_empty() || body.len() % (WORDS_PER_BLOCK * 4) != 0 {
+            return None;
+        }
+        let blocks = body
+            .chunks_exact(4)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect();
+        Some(Self::from_words(blocks))
+    }
+
+    fn num_blocks(&self) -> usize {
+        self.blocks.len() / WORDS_PER_BLOCK
+    }
+
+    /// Pick the block for a hash from its high 32 bits, multiplied into the block range so the
+    /// distribution stays uniform without a modulo.
+    #[inline]
+    fn block_index(&self, hash: u64) -> usize {
+        (((hash >> 32) * self.num_blocks() as u64) >> 32) as usize
+    }
+
+    /// Derive, for each of the eight words, the bit position (0..32) from the low 32 bits of the
+    /// hash by taking the top 5 bits of `key * SALT[i]`.
+    #[inline]
+    fn mask(hash: u64) -> [u32; WORDS_PER_BLOCK] {
+        let key = hash as u32;
+        let mut mask = [0u32; WORDS_PER_BLOCK];
+        for i in 0..WORDS_PER_BLOCK {
+            let pos = key.wrapping_mul(SALT[i]) >> 27;
+            mask[i] = 1 << pos;
+        }
+        mask
+    }
+
+    /// Insert a hash by setting all eight bits of its block.
+    pub fn insert_hash(&mut self, hash: u64) {
+        let offset = self.block_index(hash) * WORDS_PER_BLOCK;
+        let mask = Self::mask(hash);
+        for i in 0..WORDS_PER_BLOCK {
+            self.blocks[offset + i] |= mask[i];
+        }
+    }
+
+    /// Probe a hash. Returns true when all eight bits of its block are set; false positives are
+    /// possible, false negatives are not.
+    pub fn contains_hash(&self, hash: u64) -> bool {
+        let offset = self.block_index(hash) * WORDS_PER_BLOCK;
+        let mask = Self::mask(hash);
+        (0..WORDS_PER_BLOCK).all(|i| self.blocks[offset + i] & mask[i] == mask[i])
+    }
+}
+
+/// Build the serialized bloom filter bytes for a column from the hashes of its values, sized for
+/// `num_blocks` blocks. The payload carries the [`SBBF_VERSION`] tag so a reader can tell this
+/// layout apart from the legacy databend encoding. `BloomFilterIndexer` does not call it; the
+/// indexes it writes keep the legacy encoding.
+pub fn build_column_filter(hashes: impl IntoIterator<Item = u64>, num_blocks: usize) -> Vec<u8> {
+    let mut sbbf = Sbbf::with_num_blocks(num_blocks);
+    for h in hashes {
+        sbbf.insert_hash(h);
+    }
+    sbbf.to_bytes()
+}
+
+/// Probe the serialized filter `bytes` for a value `hash`. Returns true when the value may be
+/// present (false positives allowed, no false negatives).
+///
+/// A payload whose version tag is not the SBBF layout — e.g. an index written by the legacy
+/// databend encoding — returns true so the block is kept and the caller stays correct (it can then
+/// fall back to its own reader). `BloomFilterIndexer::maybe_true` does not call it.
+pub fn column_filter_maybe_contains(bytes: &[u8], hash: u64) -> bool {
+    match Sbbf::from_bytes(bytes) {
+        Some(sbbf) => sbbf.contains_hash(hash),
+        None => true,
+    }
+}
+
+/// A sane lower bound on the target FPP. A request for zero (or a negative / NaN) false-positive
+/// probability is physically impossible and would otherwise size a near-infinite allocation, so it
+/// is floored here.
+const MIN_FPP: f64 = 1.0e-6;
+
+/// Size the number of blocks from an expected distinct-value count and a target FPP.
+///
+/// Follows the bit-count heuristic `m = -ndv * ln(fpp) / ln(2)^2`, rounded up to a whole number of
+/// 256-bit blocks (at least one). `fpp` is clamped to the interval `[MIN_FPP, 1.0)` and a
+/// non-finite value falls back to `MIN_FPP`, so a bogus configuration can never request an
+/// unbounded filter.
+pub fn num_blocks(ndv: u64, fpp: f64) -> usize {
+    let fpp = if fpp.is_finite() {
+        fpp.clamp(MIN_FPP, 1.0 - f64::EPSILON)
+    } else {
+        MIN_FPP
+    };
+    let bits = -(ndv as f64) * fpp.ln() / (std::f64::consts::LN_2 * std::f64::consts::LN_2);
+    let bits_per_block = (WORDS_PER_BLOCK * 32) as f64;
+    ((bits / bits_per_block).ceil() as usize).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a cheap, deterministic 64-bit mixer so the tests do not depend on a particular hash impl
+    fn hash(mut x: u64) -> u64 {
+        x ^= x >> 33;
+        x = x.wrapping_mul(0xff51_afd7_ed55_8ccd);
+        x ^= x >> 33;
+        x = x.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+        x ^= x >> 33;
+        x
+    }
+
+    #[test]
+    fn insert_then_contains_round_trips() {
+        let mut sbbf = Sbbf::with_ndv_fpp(1_000, 0.01);
+        for i in 0..1_000u64 {
+            sbbf.insert_hash(hash(i));
+        }
+        for i in 0..1_000u64 {
+            assert!(sbbf.contains_hash(hash(i)), "missing inserted value {i}");
+        }
+    }
+
+    #[test]
+    fn no_false_negatives() {
+        // whatever the block count, every inserted hash must still be reported present
+        let mut sbbf = Sbbf::with_num_blocks(1);
+        for i in 0..10_000u64 {
+            let h = hash(i.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+            sbbf.insert_hash(h);
+            assert!(sbbf.contains_hash(h));
+        }
+    }
+
+    #[test]
+    fn known_block_and_bit_layout() {
+        // a single-block filter pins the salt / shift arithmetic against hand-computed values.
+        let mut sbbf = Sbbf::with_num_blocks(1);
+        let h: u64 = 0x0123_4567_89ab_cdef;
+        sbbf.insert_hash(h);
+
+        let key = h as u32;
+        for (i, salt) in SALT.iter().enumerate() {
+            let pos = key.wrapping_mul(*salt) >> 27;
+            assert_eq!(
+                sbbf.words()[i] & (1 << pos),
+                1 << pos,
+                "bit {pos} of word {i} should be set"
+            );
+        }
+        assert!(sbbf.contains_hash(h));
+    }
+
+    #[test]
+    fn serialisation_round_trips_with_version_tag() {
+        let mut sbbf = Sbbf::with_num_blocks(4);
+        for i in 0..100u64 {
+            sbbf.insert_hash(hash(i));
+        }
+        let bytes = sbbf.to_bytes();
+        assert_eq!(
+            u16::from_le_bytes([bytes[0], bytes[1]]),
+            SBBF_VERSION,
+            "version tag must lead the payload"
+        );
+        let decoded = Sbbf::from_bytes(&bytes).expect("current version should decode");
+        assert_eq!(sbbf, decoded);
+    }
+
+    #[test]
+    fn from_bytes_rejects_unknown_version() {
+        // a legacy (non-SBBF) encoding must be declined so the caller can fall back
+        let mut bytes = Sbbf::with_num_blocks(1).to_bytes();
+        bytes[0] = bytes[0].wrapping_add(1);
+        assert!(Sbbf::from_bytes(&bytes).is_none());
+    }
+
+    #[test]
+    fn column_filter_build_then_probe() {
+        let values: Vec<u64> = (0..500u64).map(hash).collect();
+        let bytes = build_column_filter(values.iter().copied(), num_blocks(500, 0.01));
+        // every built value must be reported present
+        for h in &values {
+            assert!(column_filter_maybe_contains(&bytes, *h));
+        }
+    }
+
+    #[test]
+    fn legacy_payload_is_kept() {
+        // a payload this layout does not recognise must answer "maybe present" so the block is
+        // kept and the caller can fall back to the legacy reader
+        let legacy = vec![0xffu8; 16];
+        assert!(column_filter_maybe_contains(&legacy, hash(1)));
+    }
+
+    #[test]
+    fn num_blocks_is_bounded_for_degenerate_fpp() {
+        // zero / negative / NaN FPP must not blow up the allocation
+        assert!(num_blocks(1_000, 0.0) <= num_blocks(1_000, MIN_FPP));
+        assert!(num_blocks(1_000, -1.0) <= num_blocks(1_000, MIN_FPP));
+        assert!(num_blocks(1_000, f64::NAN) <= num_blocks(1_000, MIN_FPP));
+        assert_eq!(num_blocks(0, 0.01), 1);
+        // a looser FPP never needs more blocks than a tighter one
+        assert!(num_blocks(1_000, 0.1) <= num_blocks(1_000, 0.001));
+    }
+}